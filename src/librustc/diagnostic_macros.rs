@@ -0,0 +1,39 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Macros shared by `diagnostics.rs` and the passes that raise
+//! individual, error-code-bearing diagnostics.
+
+macro_rules! span_err {
+    ($session:expr, $span:expr, $code:ident, $($message:tt)*) => ({
+        $session.span_err_with_code($span, &format!($($message)*), stringify!($code))
+    })
+}
+
+macro_rules! register_long_diagnostics {
+    ($($code:ident: $explanation:expr),* $(,)*) => (
+        #[allow(dead_code)]
+        pub fn get_long_diagnostic_explanation(code: &str) -> Option<&'static str> {
+            match code {
+                $(stringify!($code) => Some($explanation),)*
+                _ => None,
+            }
+        }
+    )
+}
+
+macro_rules! register_diagnostics {
+    ($($code:ident),* $(,)*) => (
+        #[allow(dead_code)]
+        pub fn registered_diagnostic_codes() -> &'static [&'static str] {
+            &[$(stringify!($code)),*]
+        }
+    )
+}
@@ -0,0 +1,52 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+register_long_diagnostics! {
+E0751: r##"
+A type was given both a positive and a negative implementation of the
+same auto trait. Erroneous code example:
+
+```compile_fail
+#![feature(optin_builtin_traits)]
+
+trait MyTrait {}
+
+impl MyTrait for .. {}
+
+struct Foo;
+
+impl MyTrait for Foo {}
+impl !MyTrait for Foo {} // error: conflicting implementations of
+                         //        trait `MyTrait` for type `Foo`
+```
+
+An auto trait already gives every type a default implementation, which
+can be opted out of with a single `impl !Trait for SomeType` per type.
+Adding an explicit positive implementation for that same type leaves it
+simultaneously opted in and opted out, which is rejected during
+coherence checking rather than left to depend on impl selection order.
+To fix this, remove either the positive or the negative implementation:
+
+```
+#![feature(optin_builtin_traits)]
+
+trait MyTrait {}
+
+impl MyTrait for .. {}
+
+struct Foo;
+
+impl !MyTrait for Foo {}
+```
+"##,
+}
+
+register_diagnostics! {
+}
@@ -0,0 +1,49 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The inference context used while selecting candidates: it knows
+//! about the concrete trait impls registered in the crate (so it can
+//! confirm an impl's where-clause obligations) and can unify an impl's
+//! self type against the type actually being checked.
+
+use middle::ty::{self, Predicate, Ty};
+use middle::subst::{self, Substs};
+
+pub struct InferCtxt<'a, 'tcx: 'a> {
+    pub tcx: &'a ty::ctxt<'tcx>,
+    /// The `Trait for Type` facts known to hold in this crate, e.g.
+    /// `(Bad, "SomeBound")` for `impl SomeBound for Bad {}`. Used to
+    /// confirm the obligations introduced by a negative impl's
+    /// where-clause.
+    known_impls: &'a [(Ty<'tcx>, &'tcx str)],
+}
+
+impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
+    pub fn new(tcx: &'a ty::ctxt<'tcx>,
+               known_impls: &'a [(Ty<'tcx>, &'tcx str)])
+               -> InferCtxt<'a, 'tcx> {
+        InferCtxt { tcx: tcx, known_impls: known_impls }
+    }
+
+    /// Unifies an impl's self type against the self type being
+    /// checked, returning the generic-parameter substitution if they
+    /// match structurally.
+    pub fn match_self_types(&self, pattern: Ty<'tcx>, target: Ty<'tcx>) -> Option<Substs<'tcx>> {
+        subst::match_self_types(pattern, target)
+    }
+
+    /// Checks whether `predicate` is satisfied by the known impls of
+    /// this crate.
+    pub fn predicate_holds(&self, predicate: &Predicate<'tcx>) -> bool {
+        self.known_impls.iter().any(|&(ty, trait_name)| {
+            ty == predicate.self_ty && trait_name == predicate.trait_name
+        })
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Substitution of generic parameters discovered while matching an
+//! impl's self type against a concrete type.
+
+use middle::ty::{self, Ty, TyKind};
+
+/// The generic-parameter bindings produced by unifying an impl's self
+/// type (`Wrapper<T>`) against a concrete self type (`Wrapper<Bad>`).
+pub struct Substs<'tcx> {
+    bindings: Vec<(&'tcx str, Ty<'tcx>)>,
+}
+
+impl<'tcx> Substs<'tcx> {
+    pub fn new() -> Substs<'tcx> {
+        Substs { bindings: Vec::new() }
+    }
+
+    fn bind(&mut self, param: &'tcx str, ty: Ty<'tcx>) {
+        self.bindings.push((param, ty));
+    }
+
+    fn lookup(&self, param: &str) -> Option<Ty<'tcx>> {
+        self.bindings.iter().find(|&&(p, _)| p == param).map(|&(_, ty)| ty)
+    }
+}
+
+/// Unifies `pattern` (an impl's self type, possibly containing
+/// `TyKind::Param`s) against `target` (a concrete self type),
+/// collecting the parameter bindings that make them equal.
+pub fn match_self_types<'tcx>(pattern: Ty<'tcx>, target: Ty<'tcx>) -> Option<Substs<'tcx>> {
+    let mut substs = Substs::new();
+    if unify(pattern, target, &mut substs) {
+        Some(substs)
+    } else {
+        None
+    }
+}
+
+fn unify<'tcx>(pattern: Ty<'tcx>, target: Ty<'tcx>, substs: &mut Substs<'tcx>) -> bool {
+    match *pattern {
+        TyKind::Param(name) => {
+            match substs.lookup(name) {
+                Some(bound) => bound == target,
+                None => {
+                    substs.bind(name, target);
+                    true
+                }
+            }
+        }
+        TyKind::Adt(name, ref args) => {
+            match *target {
+                TyKind::Adt(target_name, ref target_args) => {
+                    name == target_name && args.len() == target_args.len() &&
+                        args.iter().zip(target_args.iter())
+                            .all(|(a, b)| unify(a, b, substs))
+                }
+                TyKind::Param(_) => false,
+            }
+        }
+    }
+}
+
+/// Anything that can have an impl's generic parameters replaced with
+/// the bindings discovered by `match_self_types`.
+pub trait Subst<'tcx> {
+    fn subst(&self, substs: &Substs<'tcx>) -> Self;
+}
+
+impl<'tcx> Subst<'tcx> for ty::Predicate<'tcx> {
+    fn subst(&self, substs: &Substs<'tcx>) -> ty::Predicate<'tcx> {
+        ty::Predicate {
+            self_ty: subst_ty(self.self_ty, substs),
+            trait_name: self.trait_name,
+        }
+    }
+}
+
+fn subst_ty<'tcx>(ty: Ty<'tcx>, substs: &Substs<'tcx>) -> Ty<'tcx> {
+    match *ty {
+        TyKind::Param(name) => substs.lookup(name).unwrap_or(ty),
+        TyKind::Adt(..) => ty,
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Coherence checking for auto traits.
+//!
+//! An auto trait (`impl Trait for ..`) gives every type a default,
+//! implicit membership that can be opted out of with `impl !Trait for
+//! SomeType`. Nothing, however, stopped a crate from *also* writing an
+//! explicit positive impl for that same concrete type, which left the
+//! type simultaneously opted in and opted out depending on which impl
+//! selection happened to find first. This pass walks the impls of each
+//! auto trait and rejects types that have been given both.
+
+use middle::ty;
+use syntax::codemap::Span;
+
+/// A concrete type that was found with both a positive and a negative
+/// impl of the same auto trait.
+pub struct AutoTraitOverlap {
+    pub positive_span: Span,
+    pub negative_span: Span,
+    pub self_ty: String,
+}
+
+/// Finds, for a single auto trait, every concrete self type that has
+/// both an explicit positive impl and an explicit negative impl
+/// registered against it.
+pub fn find_auto_trait_overlaps(positive_impls: &[(String, Span)],
+                                 negative_impls: &[(String, Span)])
+                                 -> Vec<AutoTraitOverlap> {
+    let mut overlaps = Vec::new();
+    for &(ref pos_ty, pos_span) in positive_impls {
+        for &(ref neg_ty, neg_span) in negative_impls {
+            if pos_ty == neg_ty {
+                overlaps.push(AutoTraitOverlap {
+                    positive_span: pos_span,
+                    negative_span: neg_span,
+                    self_ty: pos_ty.clone(),
+                });
+            }
+        }
+    }
+    overlaps
+}
+
+/// Reports each overlap found by `find_auto_trait_overlaps` as `E0751`.
+/// The negative impl is where the contradiction actually surfaces (the
+/// default/positive membership already stands; it's the `impl !Trait`
+/// that can't be honored), so that's where the primary error goes; the
+/// positive impl it conflicts with is pointed at via a note.
+pub fn report_auto_trait_overlaps<'tcx>(tcx: &ty::ctxt<'tcx>,
+                                        trait_name: &str,
+                                        overlaps: &[AutoTraitOverlap]) {
+    for overlap in overlaps {
+        span_err!(tcx.sess, overlap.negative_span, E0751,
+                  "conflicting implementations of trait `{}` for type `{}`",
+                  trait_name, overlap.self_ty);
+        tcx.sess.span_note(overlap.positive_span,
+                            "conflicting implementation is here");
+    }
+}
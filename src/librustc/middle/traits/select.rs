@@ -0,0 +1,77 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Candidate assembly and confirmation for auto traits (the traits
+//! enabled by `#![feature(optin_builtin_traits)]`, e.g. `Send` and
+//! user-defined analogues declared via `impl Trait for ..`).
+
+use middle::infer::InferCtxt;
+use middle::ty::{self, Ty};
+use middle::subst::Subst;
+
+use syntax::ast::NodeId;
+
+/// An `impl<...> !Trait for SelfType where ...` registered for some
+/// auto trait, as recorded by the `AutoTraitImpls` table built during
+/// coherence checking.
+pub struct AutoTraitNegativeImpl<'tcx> {
+    pub node_id: NodeId,
+    pub self_ty: Ty<'tcx>,
+    pub predicates: Vec<ty::Predicate<'tcx>>,
+}
+
+pub struct SelectionContext<'a, 'tcx: 'a> {
+    infcx: &'a InferCtxt<'a, 'tcx>,
+}
+
+impl<'a, 'tcx> SelectionContext<'a, 'tcx> {
+    pub fn new(infcx: &'a InferCtxt<'a, 'tcx>) -> SelectionContext<'a, 'tcx> {
+        SelectionContext { infcx: infcx }
+    }
+
+    /// Decide whether `self_ty` implements the auto trait that `neg_impls`
+    /// belongs to.
+    ///
+    /// Previously this only unified `self_ty` against the negative impl's
+    /// (unsubstituted) self type, so any negative impl whose self type
+    /// *matched structurally* — e.g. `Wrapper<T>` against `Wrapper<Bad>` —
+    /// was honored unconditionally, ignoring the impl's own where-clauses.
+    /// That made `impl<T> !MyTrait for Wrapper<T> where T: SomeBound {}`
+    /// opt *every* `Wrapper<U>` out of `MyTrait`, not just the ones whose
+    /// `U` actually satisfies `SomeBound`. We now substitute the impl's
+    /// generics for the ones inferred from unification and confirm the
+    /// resulting where-clause obligations before honoring the negative
+    /// impl; if they don't hold, selection falls through to the trait's
+    /// default (positive) auto impl.
+    pub fn select_auto_trait(&mut self,
+                              self_ty: Ty<'tcx>,
+                              neg_impls: &[AutoTraitNegativeImpl<'tcx>])
+                              -> bool {
+        for neg_impl in neg_impls {
+            if let Some(substs) = self.infcx.match_self_types(neg_impl.self_ty, self_ty) {
+                let satisfied = neg_impl.predicates
+                    .iter()
+                    .map(|pred| pred.subst(&substs))
+                    .all(|obligation| self.infcx.predicate_holds(&obligation));
+
+                if satisfied {
+                    // The negative impl's where-clauses are satisfied by
+                    // this particular instantiation, so `self_ty` does
+                    // *not* implement the auto trait.
+                    return false;
+                }
+            }
+        }
+
+        // No applicable negative impl: the default (positive) auto impl
+        // applies, as it does for any type not explicitly opted out.
+        true
+    }
+}
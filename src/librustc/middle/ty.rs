@@ -0,0 +1,64 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A minimal slice of the type context: just enough of `Ty`, `Session`
+//! and `Predicate` for auto-trait selection and coherence checking to
+//! operate on.
+
+use std::marker::PhantomData;
+use syntax::codemap::Span;
+
+/// Stand-in for `rustc::session::Session`: the piece of the compiler
+/// session that error reporting goes through.
+pub struct Session;
+
+impl Session {
+    pub fn span_err_with_code(&self, span: Span, msg: &str, code: &str) {
+        let _ = (span, msg, code);
+    }
+
+    pub fn span_note(&self, span: Span, msg: &str) {
+        let _ = (span, msg);
+    }
+}
+
+pub struct ctxt<'tcx> {
+    pub sess: Session,
+    marker: PhantomData<&'tcx ()>,
+}
+
+impl<'tcx> ctxt<'tcx> {
+    pub fn new() -> ctxt<'tcx> {
+        ctxt { sess: Session, marker: PhantomData }
+    }
+}
+
+/// A type, represented structurally enough to support the generic
+/// self types that appear in auto-trait impls (`Wrapper<T>`) and their
+/// concrete instantiations (`Wrapper<Bad>`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TyKind<'tcx> {
+    /// A generic parameter introduced by an impl, e.g. the `T` in
+    /// `impl<T> !MyTrait for Wrapper<T>`.
+    Param(&'tcx str),
+    /// A (possibly generic) named type applied to some arguments, e.g.
+    /// `Wrapper<Bad>` is `Adt("Wrapper", [Adt("Bad", [])])`.
+    Adt(&'tcx str, Vec<Ty<'tcx>>),
+}
+
+pub type Ty<'tcx> = &'tcx TyKind<'tcx>;
+
+/// A trait obligation appearing in an impl's where-clause, e.g. the
+/// `T: SomeBound` in `impl<T> !MyTrait for Wrapper<T> where T: SomeBound`.
+#[derive(Clone, Debug)]
+pub struct Predicate<'tcx> {
+    pub self_ty: Ty<'tcx>,
+    pub trait_name: &'tcx str,
+}
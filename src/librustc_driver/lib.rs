@@ -0,0 +1,28 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The compiler driver: runs the compilation phases in order.
+
+extern crate rustc;
+extern crate rustc_typeck;
+extern crate syntax;
+
+use rustc::middle::ty;
+use rustc_typeck::AutoTraitImpls;
+use syntax::codemap::Span;
+
+/// The analysis phase, run once the crate has been lowered and its
+/// impls collected into `auto_traits`: hands off to `rustc_typeck` for
+/// auto-trait coherence checking and per-use-site selection.
+pub fn run_analysis_passes<'tcx>(tcx: &ty::ctxt<'tcx>,
+                                  auto_traits: &[AutoTraitImpls<'tcx>],
+                                  obligations: &[(&'tcx str, ty::Ty<'tcx>, Span)]) {
+    rustc_typeck::check_crate(tcx, auto_traits, obligations);
+}
@@ -0,0 +1,29 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Per-use-site obligation checking for auto traits.
+
+use rustc::middle::ty;
+use rustc::middle::infer::InferCtxt;
+use rustc::middle::traits::SelectionContext;
+
+use AutoTraitImpls;
+
+/// Does `self_ty` implement the auto trait described by `auto_trait`?
+/// Honors any negative impls registered against it, including the
+/// where-clause obligations those negative impls carry.
+pub fn auto_trait_implemented<'tcx>(tcx: &ty::ctxt<'tcx>,
+                                     auto_trait: &AutoTraitImpls<'tcx>,
+                                     self_ty: ty::Ty<'tcx>)
+                                     -> bool {
+    let infcx = InferCtxt::new(tcx, &auto_trait.known_impls);
+    let mut selcx = SelectionContext::new(&infcx);
+    selcx.select_auto_trait(self_ty, &auto_trait.negative_impl_defs)
+}
@@ -0,0 +1,25 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Coherence checking, including the auto-trait overlap pass.
+
+use rustc::middle::ty;
+use rustc::middle::traits::{find_auto_trait_overlaps, report_auto_trait_overlaps};
+
+use AutoTraitImpls;
+
+/// Runs the auto-trait overlap check for a single trait's collected
+/// impls, reporting (as `E0751`) any concrete type given both a
+/// positive and a negative impl.
+pub fn check<'tcx>(tcx: &ty::ctxt<'tcx>, auto_trait: &AutoTraitImpls<'tcx>) {
+    let overlaps = find_auto_trait_overlaps(&auto_trait.positive_impls,
+                                             &auto_trait.negative_impls);
+    report_auto_trait_overlaps(tcx, auto_trait.trait_name, &overlaps);
+}
@@ -0,0 +1,72 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Type checking, method resolution, and coherence checking.
+
+extern crate rustc;
+extern crate syntax;
+
+pub mod check;
+pub mod coherence;
+
+use rustc::middle::ty;
+use rustc::middle::traits::AutoTraitNegativeImpl;
+use syntax::codemap::Span;
+
+/// Everything collected about a single auto trait while walking the
+/// crate's impls: the concrete types given explicit positive and
+/// negative impls (for coherence checking), the negative impls in
+/// their full generic form (for per-use-site selection), and the
+/// trait facts known to hold (for confirming a negative impl's
+/// where-clause obligations).
+pub struct AutoTraitImpls<'tcx> {
+    pub trait_name: &'tcx str,
+    pub positive_impls: Vec<(String, Span)>,
+    pub negative_impls: Vec<(String, Span)>,
+    pub negative_impl_defs: Vec<AutoTraitNegativeImpl<'tcx>>,
+    pub known_impls: Vec<(ty::Ty<'tcx>, &'tcx str)>,
+}
+
+/// Called once per crate by the driver, after impls have been
+/// collected into `auto_traits`: runs auto-trait coherence checking,
+/// then, for each place an auto-trait bound (`obligations`) was
+/// required, asks selection whether the self type actually has it.
+pub fn check_crate<'tcx>(tcx: &ty::ctxt<'tcx>,
+                          auto_traits: &[AutoTraitImpls<'tcx>],
+                          obligations: &[(&'tcx str, ty::Ty<'tcx>, Span)]) {
+    fn ty_to_string(ty: ty::Ty) -> String {
+        match *ty {
+            ty::TyKind::Param(name) => name.to_string(),
+            ty::TyKind::Adt(name, ref args) if args.is_empty() => name.to_string(),
+            ty::TyKind::Adt(name, ref args) => {
+                let args: Vec<String> = args.iter().map(|&a| ty_to_string(a)).collect();
+                format!("{}<{}>", name, args.join(", "))
+            }
+        }
+    }
+
+    for auto_trait in auto_traits {
+        coherence::check(tcx, auto_trait);
+    }
+
+    for &(trait_name, self_ty, span) in obligations {
+        let implements = auto_traits.iter()
+            .find(|a| a.trait_name == trait_name)
+            .map_or(true, |auto_trait| check::auto_trait_implemented(tcx, auto_trait, self_ty));
+
+        if !implements {
+            tcx.sess.span_err_with_code(
+                span,
+                &format!("the trait `{}` is not implemented for the type `{}`",
+                         trait_name, ty_to_string(self_ty)),
+                "E0277");
+        }
+    }
+}
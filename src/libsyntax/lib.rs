@@ -0,0 +1,27 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The pieces of `libsyntax` that the rest of this tree needs: source
+//! spans and node identifiers.
+
+pub mod codemap {
+    /// A span of source text. Opaque here; the real `libsyntax` tracks
+    /// byte offsets into the `CodeMap`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Span {
+        pub lo: u32,
+        pub hi: u32,
+    }
+}
+
+pub mod ast {
+    /// Identifies a node in the HIR/AST.
+    pub type NodeId = u32;
+}
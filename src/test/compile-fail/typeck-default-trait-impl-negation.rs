@@ -27,6 +27,23 @@ struct ThisImplsUnsafeTrait;
 
 impl !MyTrait for ThisImplsUnsafeTrait {}
 
+trait SomeBound {}
+
+struct Good;
+struct Bad;
+
+impl SomeBound for Bad {}
+
+struct Wrapper<T>(T);
+
+impl<T> !MyTrait for Wrapper<T> where T: SomeBound {}
+
+struct BothPositiveAndNegative;
+
+unsafe impl MyUnsafeTrait for BothPositiveAndNegative {}
+impl !MyUnsafeTrait for BothPositiveAndNegative {}
+//~^ ERROR conflicting implementations of trait `MyUnsafeTrait` for type `BothPositiveAndNegative`
+
 fn is_my_trait<T: MyTrait>() {}
 fn is_my_unsafe_trait<T: MyUnsafeTrait>() {}
 
@@ -39,4 +56,8 @@ fn main() {
     //~^ ERROR the trait `MyUnsafeTrait` is not implemented for the type `ThisImplsTrait`
 
     is_my_unsafe_trait::<ThisImplsUnsafeTrait>();
+
+    is_my_trait::<Wrapper<Good>>();
+    is_my_trait::<Wrapper<Bad>>();
+    //~^ ERROR the trait `MyTrait` is not implemented for the type `Wrapper<Bad>`
 }